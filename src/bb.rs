@@ -1,10 +1,46 @@
 //! A bit-banged implementation of the MDIO traits.
 
+pub mod bus;
+
 use crate::{Read, Write};
 use embedded_hal::digital::v2::{InputPin, OutputPin};
 use embedded_hal::timer::{CountDown, Periodic};
 use nb::block;
 
+/// A GPIO pin that can be switched between a driven output and a released, high-impedance input.
+///
+/// The MDIO data line is shared between master and PHY, and must be actively released by the
+/// master (tristated) during the turnaround and data phases of a read so that the PHY (or, on
+/// true open-drain/tristate GPIOs, nothing at all) is free to drive it. Implement this for any
+/// pin type capable of switching direction; a blanket implementation is *not* provided, since
+/// `embedded-hal`'s `InputPin`/`OutputPin` alone give no way to switch between the two.
+pub trait TristatePin<E>: InputPin<Error = E> + OutputPin<Error = E> {
+    /// Switch the pin to a high-impedance input, releasing the line.
+    fn set_as_input(&mut self) -> Result<(), E>;
+    /// Switch the pin back to a driven output.
+    fn set_as_output(&mut self) -> Result<(), E>;
+}
+
+/// Errors that might occur while performing an MDIO read or write.
+///
+/// `Read` and `Write` share this single error type (rather than `Read` alone getting a distinct
+/// one) so that blanket implementations built on top of both traits, such as `miim::c45`, don't
+/// need to reconcile two different associated error types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E> {
+    /// An error occurred while driving or sampling the MDIO or MDC pins.
+    Pin(E),
+    /// No PHY responded during the turnaround of a read, i.e. the line was not pulled low as
+    /// expected.
+    NoResponse,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(err: E) -> Self {
+        Error::Pin(err)
+    }
+}
+
 /// A type providing a "bit-banged" MDIO implementation around two given GPIO pins.
 ///
 /// ### Read
@@ -13,8 +49,11 @@ use nb::block;
 ///
 /// - Writes the 32-bit preamble.
 /// - Writes the 14 most significant bits of the given `ctrl_bits` in MSB order.
-/// - Waits for 2 bit times for the turn around.
-/// - Reads the 16-bit data using `u16::from_be_bytes`.
+/// - Switches the `mdio` pin to a released, high-impedance input and waits for 2 bit times for
+///   the turn around, sampling whether a PHY pulled the line low (i.e. whether one responded).
+/// - Reads the 16-bit data using `u16::from_be_bytes`, then switches `mdio` back to a driven
+///   output ready for the next frame.
+/// - Returns `Error::NoResponse` if no PHY pulled the line low during the turnaround.
 ///
 /// ### Write
 ///
@@ -29,12 +68,31 @@ use nb::block;
 /// Here's a rough example of what creating a bit-banged MDIO interface looks like. This code was
 /// taken from an application using an STM32F107 MCU to bit-bang a KSZ8863RLL switch.
 ///
+/// The `mdio` pin must implement `TristatePin` so that the driver can release the line during the
+/// turnaround and data phases of a read; here that's a thin wrapper around the HAL's own
+/// dynamically-moded pin, which can already be freely switched between input and open-drain
+/// output via `make_floating_input`/`make_open_drain_output`.
+///
 /// ```ignore
+/// struct Pa2(gpioa::PA2<Dynamic>);
+///
+/// impl mdio::bb::TristatePin<core::convert::Infallible> for Pa2 {
+///     fn set_as_input(&mut self) -> Result<(), core::convert::Infallible> {
+///         self.0.make_floating_input();
+///         Ok(())
+///     }
+///     fn set_as_output(&mut self) -> Result<(), core::convert::Infallible> {
+///         self.0.make_open_drain_output();
+///         Ok(())
+///     }
+/// }
+///
 /// let mut rcc = device.RCC.constrain();
 /// let clocks = rcc.cfgr.sysclk(CYCLE_HZ.hz()).freeze();
 /// let mut gpioa = device.GPIOA.split(&mut rcc.apb2);
 /// let mut gpioc = device.GPIOC.split(&mut rcc.apb2);
-/// let mdio = gpioa.pa2.into_open_drain_output(&mut gpioa.crl);
+/// let mut mdio = Pa2(gpioa.pa2.into_dynamic(&mut gpioa.crl));
+/// mdio.set_as_output()?;
 /// let mdc = gpioc.pc1.into_push_pull_output(&mut gpioc.crl);
 /// let timer = hal::timer::Timer::tim3(device.TIM3, &clocks, &mut rcc.apb1).start_count_down(2_500.khz());
 /// let mut mdio = mdio::bb::Mdio::new(mdio, mdc, timer);
@@ -64,13 +122,16 @@ pub struct Mdio<MdioPin, MdcPin, Clk> {
 impl<MdioPin, MdcPin, Clk, E> Mdio<MdioPin, MdcPin, Clk>
 where
     MdcPin: OutputPin<Error = E>,
-    MdioPin: InputPin<Error = E> + OutputPin<Error = E>,
+    MdioPin: TristatePin<E>,
     Clk: CountDown + Periodic,
 {
     /// The duration of the preamble in bits.
     const PREAMBLE_BITS: usize = 32;
 
     /// Create the bit-banged MDIO instance.
+    ///
+    /// The `mdio` pin should be configured as a driven output before being passed in, ready for
+    /// the first frame's preamble.
     pub fn new(mdio: MdioPin, mdc: MdcPin, clk: Clk) -> Self {
         Self { mdio, mdc, clk }
     }
@@ -133,11 +194,23 @@ where
         Ok(())
     }
 
-    /// Wait for the turnaround before reading.
-    fn turnaround(&mut self) -> Result<(), E> {
-        // TODO: Is anything needed to release Mdio pin here?
+    /// Wait for the turnaround and read the 16 data bits that follow.
+    ///
+    /// Assumes the `mdio` pin has already been released (switched to input) by the caller, and
+    /// leaves it as an input on return; the caller is responsible for restoring it to a driven
+    /// output afterwards, on both the success and error paths.
+    ///
+    /// Returns whether a PHY pulled the line low on the second turnaround bit (i.e. whether a PHY
+    /// responded at all), along with the 16 bits of data read.
+    fn turnaround_and_read(&mut self) -> Result<(bool, u16), E> {
+        self.wait_for_clk();
+        self.mdc.set_high()?;
+        self.wait_for_clk();
+        self.mdc.set_low()?;
+        let present = !self.mdio.is_high()?;
         self.pulse_clock()?;
-        self.pulse_clock()
+        let data = self.read_u16()?;
+        Ok((present, data))
     }
 
     fn read_bit(&mut self) -> Result<bool, E> {
@@ -167,31 +240,40 @@ where
 impl<MdioPin, MdcPin, Clk, E> Read for Mdio<MdioPin, MdcPin, Clk>
 where
     MdcPin: OutputPin<Error = E>,
-    MdioPin: InputPin<Error = E> + OutputPin<Error = E>,
+    MdioPin: TristatePin<E>,
     Clk: CountDown + Periodic,
 {
-    type Error = E;
+    type Error = Error<E>;
     fn read(&mut self, ctrl_bits: u16) -> Result<u16, Self::Error> {
         self.preamble()?;
         let [ctrl_a, ctrl_b] = ctrl_bits.to_be_bytes();
         self.write_u8(ctrl_a)?;
         self.write_bits(ctrl_b, 6)?;
-        self.turnaround()?;
-        self.read_u16()
+
+        self.mdio.set_as_input()?;
+        let result = self.turnaround_and_read();
+        self.mdio.set_as_output()?;
+        let (present, data) = result?;
+
+        if !present {
+            return Err(Error::NoResponse);
+        }
+        Ok(data)
     }
 }
 
 impl<MdioPin, MdcPin, Clk, E> Write for Mdio<MdioPin, MdcPin, Clk>
 where
     MdcPin: OutputPin<Error = E>,
-    MdioPin: InputPin<Error = E> + OutputPin<Error = E>,
+    MdioPin: TristatePin<E>,
     Clk: CountDown + Periodic,
 {
-    type Error = E;
+    type Error = Error<E>;
     fn write(&mut self, ctrl_bits: u16, data_bits: u16) -> Result<(), Self::Error> {
         self.preamble()?;
         self.write_u16(ctrl_bits)?;
-        self.write_u16(data_bits)
+        self.write_u16(data_bits)?;
+        Ok(())
     }
 }
 
@@ -0,0 +1,121 @@
+//! A manager for addressing multiple independently bit-banged MDIO buses.
+//!
+//! Boards frequently wire different PHYs or switches onto separate MDC/MDIO pin pairs, e.g. on
+//! multi-port switch boards where the integrated MAC cannot reach every PHY. `BusManager` owns a
+//! fixed-size collection of `bb::Mdio` instances, each keyed by a caller-chosen identifier, and
+//! dispatches `mdio::miim::{Read, Write}` operations to whichever bus is addressed.
+
+use crate::bb::Mdio;
+use crate::miim;
+use crate::miim::reg::{PhyId1, Register};
+
+/// Errors that might occur while operating on a `BusManager`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E> {
+    /// An error occurred on the addressed bus.
+    Bus(E),
+    /// No bus was found for the given identifier.
+    UnknownBus,
+}
+
+/// A fixed-size collection of independently bit-banged MDIO buses, addressed by identifier.
+///
+/// All buses share the same pin and clock types. Each bus keeps its own `Clk` instance, so the
+/// manager does not share or enforce a common clock rate across buses.
+pub struct BusManager<Id, MdioPin, MdcPin, Clk, const N: usize> {
+    buses: [(Id, Mdio<MdioPin, MdcPin, Clk>); N],
+}
+
+impl<Id, MdioPin, MdcPin, Clk, const N: usize> BusManager<Id, MdioPin, MdcPin, Clk, N>
+where
+    Id: PartialEq,
+{
+    /// Create a manager from the given identifier-keyed buses.
+    pub fn new(buses: [(Id, Mdio<MdioPin, MdcPin, Clk>); N]) -> Self {
+        Self { buses }
+    }
+
+    /// Split the manager back into its identifier-keyed buses.
+    pub fn into_buses(self) -> [(Id, Mdio<MdioPin, MdcPin, Clk>); N] {
+        self.buses
+    }
+
+    /// Borrow the bus with the given identifier, if present.
+    pub fn bus_mut(&mut self, id: &Id) -> Option<&mut Mdio<MdioPin, MdcPin, Clk>> {
+        self.buses
+            .iter_mut()
+            .find(|(bus_id, _)| bus_id == id)
+            .map(|(_, bus)| bus)
+    }
+
+    /// Iterate over the identifiers of every bus known to this manager, so that callers can
+    /// enumerate buses without already knowing every `Id` up front.
+    pub fn ids(&self) -> impl Iterator<Item = &Id> {
+        self.buses.iter().map(|(id, _)| id)
+    }
+
+    /// Read the register at `reg_addr` for the PHY at `phy_addr` on the bus with the given
+    /// identifier.
+    pub fn read<E>(&mut self, id: &Id, phy_addr: u8, reg_addr: u8) -> Result<u16, Error<E>>
+    where
+        Mdio<MdioPin, MdcPin, Clk>: miim::Read<Error = E>,
+    {
+        let bus = self.bus_mut(id).ok_or(Error::UnknownBus)?;
+        miim::Read::read(bus, phy_addr, reg_addr).map_err(Error::Bus)
+    }
+
+    /// Write `data` to the register at `reg_addr` for the PHY at `phy_addr` on the bus with the
+    /// given identifier.
+    pub fn write<E>(
+        &mut self,
+        id: &Id,
+        phy_addr: u8,
+        reg_addr: u8,
+        data: u16,
+    ) -> Result<(), Error<E>>
+    where
+        Mdio<MdioPin, MdcPin, Clk>: miim::Write<Error = E>,
+    {
+        let bus = self.bus_mut(id).ok_or(Error::UnknownBus)?;
+        miim::Write::write(bus, phy_addr, reg_addr, data).map_err(Error::Bus)
+    }
+
+    /// Scan PHY addresses `0..32` on the bus with the given identifier, invoking `found` with the
+    /// address and PHY ID 1 value of each PHY that responds with neither an all-ones nor an
+    /// all-zeros value.
+    pub fn discover<E>(&mut self, id: &Id, mut found: impl FnMut(u8, u16)) -> Result<(), Error<E>>
+    where
+        Mdio<MdioPin, MdcPin, Clk>: miim::Read<Error = E>,
+    {
+        let bus = self.bus_mut(id).ok_or(Error::UnknownBus)?;
+        for phy_addr in 0..32u8 {
+            if let Ok(phy_id1) = miim::Read::read(bus, phy_addr, PhyId1::ADDR) {
+                if phy_id1 != 0xFFFF && phy_id1 != 0x0000 {
+                    found(phy_addr, phy_id1);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Scan PHY addresses `0..32` on every bus known to this manager, invoking `found` with each
+    /// bus's identifier, PHY address and PHY ID 1 value of each PHY that responds with neither an
+    /// all-ones nor an all-zeros value.
+    ///
+    /// This is the enumerate-then-discover entry point for boards wiring multiple PHYs or
+    /// switches across separate MDC/MDIO pin pairs.
+    pub fn discover_all<E>(&mut self, mut found: impl FnMut(&Id, u8, u16))
+    where
+        Mdio<MdioPin, MdcPin, Clk>: miim::Read<Error = E>,
+    {
+        for (id, bus) in self.buses.iter_mut() {
+            for phy_addr in 0..32u8 {
+                if let Ok(phy_id1) = miim::Read::read(bus, phy_addr, PhyId1::ADDR) {
+                    if phy_id1 != 0xFFFF && phy_id1 != 0x0000 {
+                        found(id, phy_addr, phy_id1);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -8,6 +8,9 @@
 //! A blanket implementation of the `mdio::miim::{Read, Write}` traits is provided for types
 //! implementing the `mdio::{Read, Write}` traits.
 
+pub mod c45;
+pub mod reg;
+
 /// A trait for reading the standard MIIM protocol.
 ///
 /// A blanket implementation is provided for types implementing the lower-level `mdio::Read` trait.
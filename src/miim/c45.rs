@@ -0,0 +1,121 @@
+//! IEEE 802.3 Clause 45 MIIM (MDIO Manageable Device) protocol support.
+//!
+//! Clause 45 extends the 10-bit (PHY address, register address) space used by Clause 22 into a
+//! 26-bit space addressed via a 5-bit port address (PRTAD), a 5-bit device address (DEVAD, e.g.
+//! PMA/PMD is `1`, PCS is `3`) and a full 16-bit register offset within that device.
+//!
+//! Unlike Clause 22, a single logical Clause 45 transaction is made up of two MDIO frames:
+//!
+//! - An **address** frame, which loads the 16-bit register offset into the addressed device's
+//!   address register.
+//! - A **read** or **write** frame, which then reads or writes the data at that offset.
+//!
+//! A blanket implementation of the `mdio::miim::c45::{Read, Write}` traits is provided for any
+//! type implementing both of the lower-level `mdio::{Read, Write}` traits with the same `Error`
+//! type.
+
+/// A trait for reading the Clause 45 MIIM protocol.
+///
+/// A blanket implementation is provided for types implementing the lower-level `mdio::{Read,
+/// Write}` traits.
+pub trait Read {
+    /// Errors that might occur on the MIIM interface.
+    type Error;
+    /// Read the data at the given device's register offset.
+    ///
+    /// This issues an address frame followed by a read frame.
+    fn read(&mut self, prtad: u8, devad: u8, reg: u16) -> Result<u16, Self::Error>;
+
+    /// Read the data at the device's *current* address register without re-issuing an address
+    /// frame, then post-increment that address register.
+    ///
+    /// Useful for streaming a sequential block of registers following an initial `read` or
+    /// `write` call.
+    fn read_post_increment(&mut self, prtad: u8, devad: u8) -> Result<u16, Self::Error>;
+}
+
+/// A trait for writing the Clause 45 MIIM protocol.
+///
+/// A blanket implementation is provided for types implementing the lower-level `mdio::{Read,
+/// Write}` traits.
+pub trait Write {
+    /// Errors that might occur on the MIIM interface.
+    type Error;
+    /// Write the data to the given device's register offset.
+    ///
+    /// This issues an address frame followed by a write frame.
+    fn write(&mut self, prtad: u8, devad: u8, reg: u16, data: u16) -> Result<(), Self::Error>;
+}
+
+impl<T, E> Read for T
+where
+    T: crate::Read<Error = E> + crate::Write<Error = E>,
+{
+    type Error = E;
+    fn read(&mut self, prtad: u8, devad: u8, reg: u16) -> Result<u16, Self::Error> {
+        crate::Write::write(self, addr_ctrl_bits(prtad, devad), reg)?;
+        crate::Read::read(self, read_ctrl_bits(prtad, devad))
+    }
+
+    fn read_post_increment(&mut self, prtad: u8, devad: u8) -> Result<u16, Self::Error> {
+        crate::Read::read(self, read_inc_ctrl_bits(prtad, devad))
+    }
+}
+
+impl<T, E> Write for T
+where
+    T: crate::Read<Error = E> + crate::Write<Error = E>,
+{
+    type Error = E;
+    fn write(&mut self, prtad: u8, devad: u8, reg: u16, data: u16) -> Result<(), Self::Error> {
+        crate::Write::write(self, addr_ctrl_bits(prtad, devad), reg)?;
+        crate::Write::write(self, write_ctrl_bits(prtad, devad), data)
+    }
+}
+
+fn prtad_ctrl_bits(prtad: u8) -> u16 {
+    const PRTAD_OFFSET: u16 = 7;
+    ((prtad & 0b00011111) as u16) << PRTAD_OFFSET
+}
+
+fn devad_ctrl_bits(devad: u8) -> u16 {
+    const DEVAD_OFFSET: u16 = 2;
+    ((devad & 0b00011111) as u16) << DEVAD_OFFSET
+}
+
+/// Given the port and device addresses, produce the control bits for an address frame.
+///
+/// The data accompanying this frame (via `mdio::Write::write`) is the 16-bit register offset to
+/// load into the device's address register.
+pub fn addr_ctrl_bits(prtad: u8, devad: u8) -> u16 {
+    // Grouped by field (ST, OP, PRTAD, DEVAD, TA), not by nibble.
+    #[allow(clippy::unusual_byte_groupings)]
+    const ADDR_CTRL_BITS: u16 = 0b00_00_00000_00000_10;
+    ADDR_CTRL_BITS | prtad_ctrl_bits(prtad) | devad_ctrl_bits(devad)
+}
+
+/// Given the port and device addresses, produce the control bits for a write frame.
+pub fn write_ctrl_bits(prtad: u8, devad: u8) -> u16 {
+    // Grouped by field (ST, OP, PRTAD, DEVAD, TA), not by nibble.
+    #[allow(clippy::unusual_byte_groupings)]
+    const WRITE_CTRL_BITS: u16 = 0b00_01_00000_00000_10;
+    WRITE_CTRL_BITS | prtad_ctrl_bits(prtad) | devad_ctrl_bits(devad)
+}
+
+/// Given the port and device addresses, produce the control bits for a read frame.
+pub fn read_ctrl_bits(prtad: u8, devad: u8) -> u16 {
+    // Grouped by field (ST, OP, PRTAD, DEVAD, TA), not by nibble.
+    #[allow(clippy::unusual_byte_groupings)]
+    const READ_CTRL_BITS: u16 = 0b00_11_00000_00000_00;
+    READ_CTRL_BITS | prtad_ctrl_bits(prtad) | devad_ctrl_bits(devad)
+}
+
+/// Given the port and device addresses, produce the control bits for a "post-read-increment-
+/// address" read frame, allowing a block of sequential registers to be streamed without
+/// re-issuing an address frame between each register.
+pub fn read_inc_ctrl_bits(prtad: u8, devad: u8) -> u16 {
+    // Grouped by field (ST, OP, PRTAD, DEVAD, TA), not by nibble.
+    #[allow(clippy::unusual_byte_groupings)]
+    const READ_INC_CTRL_BITS: u16 = 0b00_10_00000_00000_00;
+    READ_INC_CTRL_BITS | prtad_ctrl_bits(prtad) | devad_ctrl_bits(devad)
+}
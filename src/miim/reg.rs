@@ -0,0 +1,474 @@
+//! Strongly-typed wrappers around the standard Clause 22 MIIM register set.
+//!
+//! Rather than passing around bare register addresses and shuffling raw `u16` values by hand,
+//! each standard register is given its own type implementing `Register`, with named bitfield
+//! getter/setter methods, e.g. `bmcr.set_reset(true)` or `bmsr.link_up()`. The `Phy` type then
+//! ties a `miim::{Read, Write}` implementation and a PHY address together so that the register
+//! addresses and their layouts stay encapsulated in one place.
+
+/// A standard MIIM register, encapsulating its address and raw bit layout.
+pub trait Register: From<u16> + Into<u16> {
+    /// The standard register address for this register.
+    const ADDR: u8;
+}
+
+/// Basic Mode Control Register (address `0x00`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bmcr(pub u16);
+
+impl From<u16> for Bmcr {
+    fn from(bits: u16) -> Self {
+        Bmcr(bits)
+    }
+}
+
+impl From<Bmcr> for u16 {
+    fn from(reg: Bmcr) -> Self {
+        reg.0
+    }
+}
+
+impl Register for Bmcr {
+    const ADDR: u8 = 0x00;
+}
+
+/// The link speed, as selected by the `BMCR` speed select bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Speed {
+    /// 10 Mbps.
+    Mbps10,
+    /// 100 Mbps.
+    Mbps100,
+    /// 1000 Mbps.
+    Gbps1,
+}
+
+impl Bmcr {
+    const RESET: u16 = 1 << 15;
+    const LOOPBACK: u16 = 1 << 14;
+    const SPEED_LSB: u16 = 1 << 13;
+    const AUTONEG_ENABLE: u16 = 1 << 12;
+    const POWER_DOWN: u16 = 1 << 11;
+    const ISOLATE: u16 = 1 << 10;
+    const RESTART_AUTONEG: u16 = 1 << 9;
+    const DUPLEX: u16 = 1 << 8;
+    const SPEED_MSB: u16 = 1 << 6;
+
+    /// Whether the PHY is to be software reset.
+    pub fn reset(&self) -> bool {
+        self.0 & Self::RESET != 0
+    }
+
+    /// Set whether the PHY is to be software reset.
+    ///
+    /// The PHY clears this bit itself once the reset has completed.
+    pub fn set_reset(&mut self, reset: bool) {
+        set(&mut self.0, Self::RESET, reset);
+    }
+
+    /// Whether the PHY is set to loopback mode.
+    pub fn loopback(&self) -> bool {
+        self.0 & Self::LOOPBACK != 0
+    }
+
+    /// Set whether the PHY is set to loopback mode.
+    pub fn set_loopback(&mut self, loopback: bool) {
+        set(&mut self.0, Self::LOOPBACK, loopback);
+    }
+
+    /// The link speed currently selected via the speed select bits.
+    pub fn speed(&self) -> Speed {
+        match (self.0 & Self::SPEED_MSB != 0, self.0 & Self::SPEED_LSB != 0) {
+            (false, false) => Speed::Mbps10,
+            (false, true) => Speed::Mbps100,
+            (true, false) => Speed::Gbps1,
+            (true, true) => Speed::Gbps1,
+        }
+    }
+
+    /// Set the link speed via the speed select bits.
+    pub fn set_speed(&mut self, speed: Speed) {
+        let (msb, lsb) = match speed {
+            Speed::Mbps10 => (false, false),
+            Speed::Mbps100 => (false, true),
+            Speed::Gbps1 => (true, false),
+        };
+        set(&mut self.0, Self::SPEED_MSB, msb);
+        set(&mut self.0, Self::SPEED_LSB, lsb);
+    }
+
+    /// Whether auto-negotiation is enabled.
+    pub fn autoneg_enable(&self) -> bool {
+        self.0 & Self::AUTONEG_ENABLE != 0
+    }
+
+    /// Set whether auto-negotiation is enabled.
+    pub fn set_autoneg_enable(&mut self, enable: bool) {
+        set(&mut self.0, Self::AUTONEG_ENABLE, enable);
+    }
+
+    /// Whether the PHY is powered down.
+    pub fn power_down(&self) -> bool {
+        self.0 & Self::POWER_DOWN != 0
+    }
+
+    /// Set whether the PHY is powered down.
+    pub fn set_power_down(&mut self, power_down: bool) {
+        set(&mut self.0, Self::POWER_DOWN, power_down);
+    }
+
+    /// Whether the PHY is electrically isolated from the MII/GMII.
+    pub fn isolate(&self) -> bool {
+        self.0 & Self::ISOLATE != 0
+    }
+
+    /// Set whether the PHY is electrically isolated from the MII/GMII.
+    pub fn set_isolate(&mut self, isolate: bool) {
+        set(&mut self.0, Self::ISOLATE, isolate);
+    }
+
+    /// Whether auto-negotiation is to be restarted.
+    pub fn restart_autoneg(&self) -> bool {
+        self.0 & Self::RESTART_AUTONEG != 0
+    }
+
+    /// Set whether auto-negotiation is to be restarted.
+    ///
+    /// The PHY clears this bit itself once auto-negotiation has been initiated.
+    pub fn set_restart_autoneg(&mut self, restart: bool) {
+        set(&mut self.0, Self::RESTART_AUTONEG, restart);
+    }
+
+    /// Whether full duplex is currently selected.
+    pub fn duplex(&self) -> bool {
+        self.0 & Self::DUPLEX != 0
+    }
+
+    /// Set whether full duplex is to be selected.
+    pub fn set_duplex(&mut self, full_duplex: bool) {
+        set(&mut self.0, Self::DUPLEX, full_duplex);
+    }
+}
+
+/// Basic Mode Status Register (address `0x01`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Bmsr(pub u16);
+
+impl From<u16> for Bmsr {
+    fn from(bits: u16) -> Self {
+        Bmsr(bits)
+    }
+}
+
+impl From<Bmsr> for u16 {
+    fn from(reg: Bmsr) -> Self {
+        reg.0
+    }
+}
+
+impl Register for Bmsr {
+    const ADDR: u8 = 0x01;
+}
+
+impl Bmsr {
+    const AUTONEG_COMPLETE: u16 = 1 << 5;
+    const REMOTE_FAULT: u16 = 1 << 4;
+    const AUTONEG_CAPABLE: u16 = 1 << 3;
+    const LINK_UP: u16 = 1 << 2;
+    const JABBER_DETECT: u16 = 1 << 1;
+    const EXTENDED_CAPABLE: u16 = 1 << 0;
+
+    /// Whether auto-negotiation has completed.
+    pub fn autoneg_complete(&self) -> bool {
+        self.0 & Self::AUTONEG_COMPLETE != 0
+    }
+
+    /// Whether a remote fault has been detected.
+    pub fn remote_fault(&self) -> bool {
+        self.0 & Self::REMOTE_FAULT != 0
+    }
+
+    /// Whether the PHY is capable of auto-negotiation.
+    pub fn autoneg_capable(&self) -> bool {
+        self.0 & Self::AUTONEG_CAPABLE != 0
+    }
+
+    /// Whether the link is currently up.
+    pub fn link_up(&self) -> bool {
+        self.0 & Self::LINK_UP != 0
+    }
+
+    /// Whether a jabber condition has been detected.
+    pub fn jabber_detect(&self) -> bool {
+        self.0 & Self::JABBER_DETECT != 0
+    }
+
+    /// Whether the PHY supports the extended register set.
+    pub fn extended_capable(&self) -> bool {
+        self.0 & Self::EXTENDED_CAPABLE != 0
+    }
+}
+
+/// PHY Identifier Register 1 (address `0x02`), holding bits `3..=18` of the PHY's OUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PhyId1(pub u16);
+
+impl From<u16> for PhyId1 {
+    fn from(bits: u16) -> Self {
+        PhyId1(bits)
+    }
+}
+
+impl From<PhyId1> for u16 {
+    fn from(reg: PhyId1) -> Self {
+        reg.0
+    }
+}
+
+impl Register for PhyId1 {
+    const ADDR: u8 = 0x02;
+}
+
+/// PHY Identifier Register 2 (address `0x03`), holding the OUI's remaining bits along with the
+/// model and revision numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PhyId2(pub u16);
+
+impl From<u16> for PhyId2 {
+    fn from(bits: u16) -> Self {
+        PhyId2(bits)
+    }
+}
+
+impl From<PhyId2> for u16 {
+    fn from(reg: PhyId2) -> Self {
+        reg.0
+    }
+}
+
+impl Register for PhyId2 {
+    const ADDR: u8 = 0x03;
+}
+
+/// Auto-Negotiation Advertisement Register (address `0x04`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Anar(pub u16);
+
+impl From<u16> for Anar {
+    fn from(bits: u16) -> Self {
+        Anar(bits)
+    }
+}
+
+impl From<Anar> for u16 {
+    fn from(reg: Anar) -> Self {
+        reg.0
+    }
+}
+
+impl Register for Anar {
+    const ADDR: u8 = 0x04;
+}
+
+impl Anar {
+    const REMOTE_FAULT: u16 = 1 << 13;
+    const PAUSE: u16 = 1 << 10;
+    const HUNDRED_BASE_T4: u16 = 1 << 9;
+    const HUNDRED_BASE_TX_FULL_DUPLEX: u16 = 1 << 8;
+    const HUNDRED_BASE_TX: u16 = 1 << 7;
+    const TEN_BASE_T_FULL_DUPLEX: u16 = 1 << 6;
+    const TEN_BASE_T: u16 = 1 << 5;
+
+    /// Whether a remote fault is to be advertised.
+    pub fn remote_fault(&self) -> bool {
+        self.0 & Self::REMOTE_FAULT != 0
+    }
+
+    /// Set whether a remote fault is to be advertised.
+    pub fn set_remote_fault(&mut self, remote_fault: bool) {
+        set(&mut self.0, Self::REMOTE_FAULT, remote_fault);
+    }
+
+    /// Whether symmetric PAUSE is to be advertised.
+    pub fn pause(&self) -> bool {
+        self.0 & Self::PAUSE != 0
+    }
+
+    /// Set whether symmetric PAUSE is to be advertised.
+    pub fn set_pause(&mut self, pause: bool) {
+        set(&mut self.0, Self::PAUSE, pause);
+    }
+
+    /// Whether 100BASE-T4 is to be advertised.
+    pub fn hundred_base_t4(&self) -> bool {
+        self.0 & Self::HUNDRED_BASE_T4 != 0
+    }
+
+    /// Set whether 100BASE-T4 is to be advertised.
+    pub fn set_hundred_base_t4(&mut self, supported: bool) {
+        set(&mut self.0, Self::HUNDRED_BASE_T4, supported);
+    }
+
+    /// Whether 100BASE-TX full duplex is to be advertised.
+    pub fn hundred_base_tx_full_duplex(&self) -> bool {
+        self.0 & Self::HUNDRED_BASE_TX_FULL_DUPLEX != 0
+    }
+
+    /// Set whether 100BASE-TX full duplex is to be advertised.
+    pub fn set_hundred_base_tx_full_duplex(&mut self, supported: bool) {
+        set(&mut self.0, Self::HUNDRED_BASE_TX_FULL_DUPLEX, supported);
+    }
+
+    /// Whether 100BASE-TX is to be advertised.
+    pub fn hundred_base_tx(&self) -> bool {
+        self.0 & Self::HUNDRED_BASE_TX != 0
+    }
+
+    /// Set whether 100BASE-TX is to be advertised.
+    pub fn set_hundred_base_tx(&mut self, supported: bool) {
+        set(&mut self.0, Self::HUNDRED_BASE_TX, supported);
+    }
+
+    /// Whether 10BASE-T full duplex is to be advertised.
+    pub fn ten_base_t_full_duplex(&self) -> bool {
+        self.0 & Self::TEN_BASE_T_FULL_DUPLEX != 0
+    }
+
+    /// Set whether 10BASE-T full duplex is to be advertised.
+    pub fn set_ten_base_t_full_duplex(&mut self, supported: bool) {
+        set(&mut self.0, Self::TEN_BASE_T_FULL_DUPLEX, supported);
+    }
+
+    /// Whether 10BASE-T is to be advertised.
+    pub fn ten_base_t(&self) -> bool {
+        self.0 & Self::TEN_BASE_T != 0
+    }
+
+    /// Set whether 10BASE-T is to be advertised.
+    pub fn set_ten_base_t(&mut self, supported: bool) {
+        set(&mut self.0, Self::TEN_BASE_T, supported);
+    }
+}
+
+/// Auto-Negotiation Link Partner Ability Register (address `0x05`).
+///
+/// Shares `Anar`'s bitfield layout, whose accessors are reached via `abilities`; the only
+/// accessor defined directly on this type is `link_partner_acknowledge`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Anlpar(pub u16);
+
+impl From<u16> for Anlpar {
+    fn from(bits: u16) -> Self {
+        Anlpar(bits)
+    }
+}
+
+impl From<Anlpar> for u16 {
+    fn from(reg: Anlpar) -> Self {
+        reg.0
+    }
+}
+
+impl Register for Anlpar {
+    const ADDR: u8 = 0x05;
+}
+
+impl Anlpar {
+    const ACKNOWLEDGE: u16 = 1 << 14;
+
+    /// Whether the link partner acknowledges the auto-negotiation message.
+    pub fn link_partner_acknowledge(&self) -> bool {
+        self.0 & Self::ACKNOWLEDGE != 0
+    }
+
+    /// The abilities advertised by the link partner, sharing `Anar`'s bitfield layout.
+    pub fn abilities(&self) -> Anar {
+        Anar(self.0)
+    }
+}
+
+fn set(bits: &mut u16, mask: u16, value: bool) {
+    if value {
+        *bits |= mask;
+    } else {
+        *bits &= !mask;
+    }
+}
+
+/// The OUI, model number and revision number decoded from a PHY's `PhyId1`/`PhyId2` registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhyId {
+    /// The 22-bit Organizationally Unique Identifier.
+    pub oui: u32,
+    /// The 6-bit vendor model number.
+    pub model: u8,
+    /// The 4-bit vendor model revision number.
+    pub revision: u8,
+}
+
+/// A convenience wrapper tying together a `miim::{Read, Write}` implementation and the PHY
+/// address it should target, so that register addresses and layouts stay encapsulated here
+/// rather than scattered through application code.
+pub struct Phy<M> {
+    miim: M,
+    phy_addr: u8,
+}
+
+impl<M> Phy<M> {
+    /// Create a `Phy` from the given MIIM interface and PHY address.
+    pub fn new(miim: M, phy_addr: u8) -> Self {
+        Self { miim, phy_addr }
+    }
+
+    /// Consume the `Phy`, returning the inner MIIM interface.
+    pub fn into_inner(self) -> M {
+        self.miim
+    }
+
+    /// Read the given register.
+    pub fn read_reg<R>(&mut self) -> Result<R, M::Error>
+    where
+        M: crate::miim::Read,
+        R: Register,
+    {
+        let bits = crate::miim::Read::read(&mut self.miim, self.phy_addr, R::ADDR)?;
+        Ok(R::from(bits))
+    }
+
+    /// Write the given register.
+    pub fn write_reg<R>(&mut self, reg: R) -> Result<(), M::Error>
+    where
+        M: crate::miim::Write,
+        R: Register,
+    {
+        crate::miim::Write::write(&mut self.miim, self.phy_addr, R::ADDR, reg.into())
+    }
+
+    /// Read-modify-write the given register.
+    pub fn modify_reg<R, E>(&mut self, f: impl FnOnce(&mut R)) -> Result<(), E>
+    where
+        M: crate::miim::Read<Error = E> + crate::miim::Write<Error = E>,
+        R: Register,
+    {
+        let mut reg = self.read_reg::<R>()?;
+        f(&mut reg);
+        self.write_reg(reg)
+    }
+
+    /// Read both PHY identifier registers and decode them into their OUI, model and revision.
+    pub fn phy_id<E>(&mut self) -> Result<PhyId, E>
+    where
+        M: crate::miim::Read<Error = E>,
+    {
+        let id1: PhyId1 = self.read_reg()?;
+        let id2: PhyId2 = self.read_reg()?;
+        let oui = ((id1.0 as u32) << 6) | ((id2.0 as u32) >> 10);
+        let model = ((id2.0 >> 4) & 0b0011_1111) as u8;
+        let revision = (id2.0 & 0b1111) as u8;
+        Ok(PhyId {
+            oui,
+            model,
+            revision,
+        })
+    }
+}